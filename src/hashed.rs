@@ -0,0 +1,132 @@
+//! A [`KeyValuePair`] variant that caches the key's hash, for workloads that
+//! repeatedly rehash the same keys (resizing hash maps, dedup passes, hash joins).
+
+use core::{cmp::Ordering, fmt};
+use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
+
+use crate::KeyValuePair;
+
+/// Like [`KeyValuePair`], but stores a precomputed hash of the key alongside it.
+///
+/// The `Hash` impl writes the cached hash directly instead of re-hashing the key.
+/// `PartialEq`/`Eq`/`Ord` still delegate to the key alone. `eq` deliberately does
+/// *not* compare the cached hashes: two instances built via different
+/// [`BuildHasher`] instances (e.g. two separate `RandomState::new()` calls) can
+/// disagree on the hash of an equal key, and folding that into `eq` would break
+/// `Eq`'s substitutability guarantee.
+///
+/// # Invariant
+///
+/// The cached hash must match what `TKey::hash` would produce under the `BuildHasher`
+/// used to construct it. Mutating `key` without recomputing `hash` (e.g. via
+/// [`HashedKeyValuePair::new`]) breaks this invariant.
+pub struct HashedKeyValuePair<TKey, TValue> {
+    hash: u64,
+    pub key: TKey,
+    pub value: TValue,
+}
+
+impl<TKey: Hash, TValue> HashedKeyValuePair<TKey, TValue> {
+    /// Creates a new pair, computing the key's hash once using `build_hasher`.
+    pub fn new<S: BuildHasher>(key: TKey, value: TValue, build_hasher: &S) -> Self {
+        let hash = build_hasher.hash_one(&key);
+
+        Self { hash, key, value }
+    }
+
+    /// Returns the cached hash of the key.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl<TKey: Hash, TValue> From<KeyValuePair<TKey, TValue>> for HashedKeyValuePair<TKey, TValue> {
+    fn from(pair: KeyValuePair<TKey, TValue>) -> Self {
+        Self::new(
+            pair.key,
+            pair.value,
+            &BuildHasherDefault::<std::collections::hash_map::DefaultHasher>::default(),
+        )
+    }
+}
+
+impl<TKey: PartialEq, TValue> PartialEq for HashedKeyValuePair<TKey, TValue> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<TKey: Eq, TValue> Eq for HashedKeyValuePair<TKey, TValue> {}
+
+impl<TKey: PartialOrd, TValue> PartialOrd for HashedKeyValuePair<TKey, TValue> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.key.partial_cmp(&other.key)
+    }
+}
+
+impl<TKey: Ord, TValue> Ord for HashedKeyValuePair<TKey, TValue> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl<TKey, TValue> Hash for HashedKeyValuePair<TKey, TValue> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash)
+    }
+}
+
+impl<TKey: fmt::Debug, TValue: fmt::Debug> fmt::Debug for HashedKeyValuePair<TKey, TValue> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HashedKeyValuePair")
+            .field("hash", &self.hash)
+            .field("key", &self.key)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<TKey: Clone, TValue: Clone> Clone for HashedKeyValuePair<TKey, TValue> {
+    fn clone(&self) -> Self {
+        Self {
+            hash: self.hash,
+            key: self.key.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<TKey: Copy, TValue: Copy> Copy for HashedKeyValuePair<TKey, TValue> {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::RandomState;
+
+    use super::HashedKeyValuePair;
+
+    #[derive(Debug)]
+    struct NonOrderedType;
+
+    #[test]
+    fn hash_is_cached_and_eq_is_respected() {
+        let build_hasher = RandomState::new();
+
+        let a = HashedKeyValuePair::new(1, NonOrderedType, &build_hasher);
+        let b = HashedKeyValuePair::new(1, NonOrderedType, &build_hasher);
+        let c = HashedKeyValuePair::new(2, NonOrderedType, &build_hasher);
+
+        assert_eq!(a.hash(), b.hash());
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn eq_ignores_cached_hash_from_independent_hashers() {
+        let a = HashedKeyValuePair::new(1, NonOrderedType, &RandomState::new());
+        let b = HashedKeyValuePair::new(1, NonOrderedType, &RandomState::new());
+
+        // Two independently-seeded `RandomState`s will almost certainly disagree on
+        // the cached hash of an equal key; `eq` must still report them equal.
+        assert_eq!(a, b);
+    }
+}
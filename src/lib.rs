@@ -24,6 +24,25 @@
 
 use core::{fmt, hash::Hash};
 
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+
+#[cfg(feature = "rkyv")]
+pub use rkyv_impl::ArchivedKeyValuePair;
+
+mod hashed;
+
+pub use hashed::HashedKeyValuePair;
+
+mod ord_key;
+
+pub use ord_key::OrdKey;
+
+mod priority_queue;
+
+pub use priority_queue::KvpPriorityQueue;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyValuePair<TKey, TValue> {
     pub key: TKey,
     pub value: TValue,
@@ -35,16 +54,34 @@ impl<TKey, TValue> KeyValuePair<TKey, TValue> {
     }
 }
 
-impl<TKey: PartialEq, TValue> PartialEq for KeyValuePair<TKey, TValue> {
-    fn eq(&self, other: &Self) -> bool {
+impl<TKey, TValue> KeyValuePair<OrdKey<TKey>, TValue> {
+    /// Creates a pair keyed on `key`, wrapped in [`OrdKey`] so it can be used in
+    /// ordered or hashed collections even when `TKey` is a float.
+    pub fn new_ord(key: TKey, value: TValue) -> Self {
+        Self::new(OrdKey(key), value)
+    }
+}
+
+/// Compares the keys of two pairs, even when they carry different key/value types
+/// (e.g. an owned-key pair against a borrowed-key pair), as long as `TKey: PartialEq<UKey>`.
+impl<TKey, TValue, UKey, UValue> PartialEq<KeyValuePair<UKey, UValue>> for KeyValuePair<TKey, TValue>
+where
+    TKey: PartialEq<UKey>,
+{
+    fn eq(&self, other: &KeyValuePair<UKey, UValue>) -> bool {
         self.key == other.key
     }
 }
 
 impl<TKey: Eq, TValue> Eq for KeyValuePair<TKey, TValue> {}
 
-impl<TKey: PartialOrd, TValue> PartialOrd for KeyValuePair<TKey, TValue> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+/// Compares the keys of two pairs, even when they carry different key/value types,
+/// as long as `TKey: PartialOrd<UKey>`.
+impl<TKey, TValue, UKey, UValue> PartialOrd<KeyValuePair<UKey, UValue>> for KeyValuePair<TKey, TValue>
+where
+    TKey: PartialOrd<UKey>,
+{
+    fn partial_cmp(&self, other: &KeyValuePair<UKey, UValue>) -> Option<std::cmp::Ordering> {
         self.key.partial_cmp(&other.key)
     }
 }
@@ -61,6 +98,38 @@ impl<TKey: Hash, TValue> Hash for KeyValuePair<TKey, TValue> {
     }
 }
 
+/// Lets a pair be found by its bare key in collections that key off `TKey` via
+/// `Borrow`, without constructing a whole pair.
+///
+/// This also gives `TKey` the `equivalent` crate's `Equivalent`/`Comparable` impls for
+/// free (when that optional dependency is enabled), since its blanket impls key off
+/// `Borrow` the same way.
+impl<TKey, TValue> std::borrow::Borrow<TKey> for KeyValuePair<TKey, TValue> {
+    fn borrow(&self) -> &TKey {
+        &self.key
+    }
+}
+
+/// Lets a `KeyValuePair<String, _>` be found by `&str`, the same way a bare `String` key
+/// would be — e.g. `set.get("some-key")` against a `HashSet<KeyValuePair<String, _>>`.
+///
+/// A blanket `impl<Q> Borrow<Q> for KeyValuePair<TKey, TValue> where TKey: Borrow<Q>`
+/// would be the generic version of this, but it conflicts with `std`'s own
+/// `impl<T> Borrow<T> for T`: coherence can't rule out `Q` being instantiated to
+/// `KeyValuePair<TKey, TValue>` itself. This concrete impl sidesteps that the same way
+/// `std` does for `String`/`str`, by not being generic over the borrowed form.
+///
+/// This impl is also what makes `str: Equivalent<KeyValuePair<String, TValue>>` and
+/// `str: Comparable<_>` hold (under the `equivalent` feature): the `equivalent` crate's
+/// own blanket impls key off `Borrow` exactly like this, so `&str` gets genuine
+/// heterogeneous lookup without this crate implementing `Equivalent`/`Comparable`
+/// directly — doing so here would in fact conflict with those blanket impls.
+impl<TValue> std::borrow::Borrow<str> for KeyValuePair<String, TValue> {
+    fn borrow(&self) -> &str {
+        self.key.borrow()
+    }
+}
+
 impl<TKey: fmt::Debug, TValue: fmt::Debug> fmt::Debug for KeyValuePair<TKey, TValue> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Info")
@@ -85,6 +154,7 @@ impl<TKey: Copy, TValue: Copy> Copy for KeyValuePair<TKey, TValue> {}
 mod tests {
     use crate::KeyValuePair;
 
+    #[derive(Debug)]
     struct NonOrderedType;
 
     #[test]
@@ -115,4 +185,97 @@ mod tests {
             vec![0, 1, 2, 6]
         );
     }
+
+    #[test]
+    fn cross_type_eq_compares_keys_only() {
+        let owned = KeyValuePair {
+            key: String::from("hello"),
+            value: NonOrderedType,
+        };
+        let borrowed = KeyValuePair {
+            key: "hello",
+            value: NonOrderedType,
+        };
+
+        assert_eq!(owned, borrowed);
+    }
+
+    struct Meters(f64);
+    struct Feet(f64);
+
+    impl PartialEq<Feet> for Meters {
+        fn eq(&self, other: &Feet) -> bool {
+            self.0 == other.0 / 3.28084
+        }
+    }
+
+    impl PartialOrd<Feet> for Meters {
+        fn partial_cmp(&self, other: &Feet) -> Option<std::cmp::Ordering> {
+            self.0.partial_cmp(&(other.0 / 3.28084))
+        }
+    }
+
+    #[test]
+    fn cross_type_ord_compares_keys_only() {
+        let one_meter = KeyValuePair {
+            key: Meters(1.0),
+            value: NonOrderedType,
+        };
+        let one_foot = KeyValuePair {
+            key: Feet(1.0),
+            value: NonOrderedType,
+        };
+
+        assert_eq!(
+            one_meter.partial_cmp(&one_foot),
+            Some(std::cmp::Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn borrow_supports_heterogeneous_key_lookup() {
+        use std::borrow::Borrow;
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(KeyValuePair {
+            key: String::from("hello"),
+            value: NonOrderedType,
+        });
+
+        assert!(set.contains("hello"));
+
+        let pair = set.iter().next().unwrap();
+        let borrowed: &str = pair.borrow();
+        assert_eq!(borrowed, "hello");
+    }
+
+    #[cfg(feature = "equivalent")]
+    #[test]
+    fn str_is_equivalent_and_comparable_to_string_keyed_pair() {
+        use equivalent::{Comparable, Equivalent};
+
+        let pair = KeyValuePair {
+            key: String::from("hello"),
+            value: NonOrderedType,
+        };
+
+        assert!("hello".equivalent(&pair));
+        assert!(!"goodbye".equivalent(&pair));
+        assert_eq!("hello".compare(&pair), std::cmp::Ordering::Equal);
+        assert_eq!("a".compare(&pair), std::cmp::Ordering::Less);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips_as_flat_key_value_json() {
+        let pair = KeyValuePair::new(1, String::from("hello"));
+
+        let json = serde_json::to_string(&pair).unwrap();
+        assert_eq!(json, r#"{"key":1,"value":"hello"}"#);
+
+        let deserialized: KeyValuePair<i32, String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.key, pair.key);
+        assert_eq!(deserialized.value, pair.value);
+    }
 }
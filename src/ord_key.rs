@@ -0,0 +1,87 @@
+//! An opt-in key wrapper giving IEEE floats a total [`Eq`]/[`Ord`]/[`Hash`], so they can
+//! be used as a [`KeyValuePair`](crate::KeyValuePair) key in ordered or hashed
+//! collections.
+
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+/// Wraps a float so it can be used as the key of a [`KeyValuePair`](crate::KeyValuePair)
+/// in a [`BinaryHeap`](std::collections::BinaryHeap), [`BTreeMap`](std::collections::BTreeMap)
+/// or [`HashSet`](std::collections::HashSet).
+///
+/// `NaN` compares equal to itself and sorts consistently (matching
+/// [`f32::total_cmp`]/[`f64::total_cmp`]). Hashing uses the float's bit pattern, with
+/// `-0.0` normalized to `+0.0` so the two zeroes hash identically.
+#[derive(Debug, Clone, Copy)]
+pub struct OrdKey<T>(pub T);
+
+macro_rules! impl_ord_key {
+    ($float:ty) => {
+        impl PartialEq for OrdKey<$float> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl Eq for OrdKey<$float> {}
+
+        impl PartialOrd for OrdKey<$float> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for OrdKey<$float> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for OrdKey<$float> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                let normalized = if self.0 == 0.0 { 0.0 } else { self.0 };
+                normalized.to_bits().hash(state);
+            }
+        }
+    };
+}
+
+impl_ord_key!(f32);
+impl_ord_key!(f64);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::OrdKey;
+
+    fn hash_of<T: Hash>(value: T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn nan_is_equal_to_itself() {
+        assert_eq!(OrdKey(f64::NAN), OrdKey(f64::NAN));
+    }
+
+    #[test]
+    fn negative_and_positive_zero_hash_the_same() {
+        assert_eq!(hash_of(OrdKey(0.0_f64)), hash_of(OrdKey(-0.0_f64)));
+    }
+
+    #[test]
+    fn ordering_matches_total_cmp() {
+        let mut values = [OrdKey(1.0_f64), OrdKey(f64::NAN), OrdKey(-1.0_f64), OrdKey(0.0_f64)];
+        values.sort();
+
+        assert_eq!(values[0], OrdKey(-1.0_f64));
+        assert_eq!(values[1], OrdKey(0.0_f64));
+        assert_eq!(values[2], OrdKey(1.0_f64));
+        assert!(values[3].0.is_nan());
+    }
+}
@@ -0,0 +1,260 @@
+//! An indexed priority queue keyed on [`KeyValuePair`].
+//!
+//! Unlike [`BinaryHeap`](std::collections::BinaryHeap), a [`KvpPriorityQueue`] can look
+//! up, change the priority of, or remove an arbitrary element by its key in
+//! `O(log n)`, by maintaining a side [`HashMap`] from key to the element's current
+//! index in the heap vector. Every swap performed while sifting updates that map for
+//! both swapped slots, so the index map never drifts out of sync with the heap.
+
+use std::{cmp::Ordering, collections::HashMap, hash::Hash};
+
+use crate::KeyValuePair;
+
+/// A priority queue of `(key, value)` pairs, ordered by `value` (the priority), that
+/// supports `O(log n)` priority updates and removal by key.
+///
+/// `pop`/`peek` return the pair with the greatest value, matching
+/// [`BinaryHeap`](std::collections::BinaryHeap)'s max-heap ordering.
+pub struct KvpPriorityQueue<TKey, TValue> {
+    heap: Vec<KeyValuePair<TKey, TValue>>,
+    indices: HashMap<TKey, usize>,
+}
+
+impl<TKey, TValue> KvpPriorityQueue<TKey, TValue>
+where
+    TKey: Eq + Hash + Clone,
+    TValue: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            heap: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the pair with the greatest value, without removing it.
+    pub fn peek(&self) -> Option<&KeyValuePair<TKey, TValue>> {
+        self.heap.first()
+    }
+
+    /// Looks up a pair by its key in `O(1)`.
+    pub fn get(&self, key: &TKey) -> Option<&KeyValuePair<TKey, TValue>> {
+        self.indices.get(key).map(|&index| &self.heap[index])
+    }
+
+    pub fn contains_key(&self, key: &TKey) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &KeyValuePair<TKey, TValue>> {
+        self.heap.iter()
+    }
+
+    /// Inserts a `(key, value)` pair, sifting it into place.
+    ///
+    /// If `key` is already present, this upserts: the existing pair's value is updated
+    /// in place (as [`change_priority`](Self::change_priority) would) instead of
+    /// pushing a second entry for the same key, which would desync `indices` from the
+    /// heap.
+    pub fn push(&mut self, key: TKey, value: TValue) {
+        if self.indices.contains_key(&key) {
+            self.change_priority(&key, value);
+            return;
+        }
+
+        let index = self.heap.len();
+        self.indices.insert(key.clone(), index);
+        self.heap.push(KeyValuePair::new(key, value));
+        self.sift_up(index);
+    }
+
+    /// Removes and returns the pair with the greatest value.
+    pub fn pop(&mut self) -> Option<KeyValuePair<TKey, TValue>> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let last = self.heap.len() - 1;
+        if last != 0 {
+            self.swap(0, last);
+        }
+
+        let popped = self.heap.pop().expect("just checked non-empty");
+        self.indices.remove(&popped.key);
+
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+
+        Some(popped)
+    }
+
+    /// Updates the value (priority) of `key`, sifting it up or down as needed.
+    ///
+    /// Returns `false` if `key` isn't present.
+    pub fn change_priority(&mut self, key: &TKey, new_value: TValue) -> bool {
+        let Some(&index) = self.indices.get(key) else {
+            return false;
+        };
+
+        let direction = new_value.cmp(&self.heap[index].value);
+        self.heap[index].value = new_value;
+
+        match direction {
+            Ordering::Greater => self.sift_up(index),
+            Ordering::Less => self.sift_down(index),
+            Ordering::Equal => {}
+        }
+
+        true
+    }
+
+    /// Removes the pair keyed by `key`, by swapping it with the last element, popping,
+    /// then re-heapifying from the freed slot.
+    pub fn remove(&mut self, key: &TKey) -> Option<KeyValuePair<TKey, TValue>> {
+        let index = *self.indices.get(key)?;
+        let last = self.heap.len() - 1;
+
+        if index != last {
+            self.swap(index, last);
+        }
+
+        let removed = self.heap.pop().expect("index was present, so heap is non-empty");
+        self.indices.remove(&removed.key);
+
+        if index < self.heap.len() {
+            self.sift_down(index);
+            self.sift_up(index);
+        }
+
+        Some(removed)
+    }
+
+    /// Swaps the elements at `i` and `j`, updating the index map for both slots.
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.indices.insert(self.heap[i].key.clone(), i);
+        self.indices.insert(self.heap[j].key.clone(), j);
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.heap[index].value > self.heap[parent].value {
+                self.swap(index, parent);
+                index = parent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        let len = self.heap.len();
+
+        loop {
+            let left = 2 * index + 1;
+            let right = 2 * index + 2;
+            let mut largest = index;
+
+            if left < len && self.heap[left].value > self.heap[largest].value {
+                largest = left;
+            }
+
+            if right < len && self.heap[right].value > self.heap[largest].value {
+                largest = right;
+            }
+
+            if largest == index {
+                break;
+            }
+
+            self.swap(index, largest);
+            index = largest;
+        }
+    }
+}
+
+impl<TKey, TValue> Default for KvpPriorityQueue<TKey, TValue>
+where
+    TKey: Eq + Hash + Clone,
+    TValue: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KvpPriorityQueue;
+
+    #[test]
+    fn pop_returns_greatest_value_first() {
+        let mut queue = KvpPriorityQueue::new();
+        queue.push("a", 1);
+        queue.push("b", 3);
+        queue.push("c", 2);
+
+        assert_eq!(queue.pop().map(|kv| kv.key), Some("b"));
+        assert_eq!(queue.pop().map(|kv| kv.key), Some("c"));
+        assert_eq!(queue.pop().map(|kv| kv.key), Some("a"));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn change_priority_sifts_up_and_down() {
+        let mut queue = KvpPriorityQueue::new();
+        queue.push("a", 1);
+        queue.push("b", 2);
+        queue.push("c", 3);
+
+        assert!(queue.change_priority(&"a", 10));
+        assert_eq!(queue.peek().map(|kv| kv.key), Some("a"));
+
+        assert!(queue.change_priority(&"a", 0));
+        assert_eq!(queue.peek().map(|kv| kv.key), Some("c"));
+
+        assert!(!queue.change_priority(&"missing", 5));
+    }
+
+    #[test]
+    fn push_on_existing_key_upserts_instead_of_duplicating() {
+        let mut queue = KvpPriorityQueue::new();
+        queue.push("a", 1);
+        queue.push("b", 2);
+
+        queue.push("a", 10);
+
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.get(&"a").map(|kv| kv.value), Some(10));
+        assert_eq!(queue.pop().map(|kv| kv.key), Some("a"));
+        assert_eq!(queue.pop().map(|kv| kv.key), Some("b"));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn get_and_remove_by_key() {
+        let mut queue = KvpPriorityQueue::new();
+        queue.push("a", 1);
+        queue.push("b", 2);
+        queue.push("c", 3);
+
+        assert_eq!(queue.get(&"b").map(|kv| kv.value), Some(2));
+
+        let removed = queue.remove(&"b");
+        assert_eq!(removed.map(|kv| kv.value), Some(2));
+        assert!(queue.get(&"b").is_none());
+
+        assert_eq!(queue.pop().map(|kv| kv.key), Some("c"));
+        assert_eq!(queue.pop().map(|kv| kv.key), Some("a"));
+    }
+}
@@ -0,0 +1,147 @@
+//! `rkyv` support for [`KeyValuePair`], gated behind the `rkyv` feature.
+//!
+//! [`ArchivedKeyValuePair`] mirrors the key-only semantics of [`KeyValuePair`]: its
+//! `PartialEq`/`Eq`/`Ord`/`Hash` impls delegate to the archived key alone, so an
+//! archived `BinaryHeap<KeyValuePair<_, _>>` keeps behaving the same once
+//! memory-mapped back.
+
+use core::{cmp::Ordering, hash::Hash};
+
+use rkyv::{out_field, Archive, Archived, Deserialize, Fallible, Serialize};
+
+use crate::KeyValuePair;
+
+/// The archived representation of a [`KeyValuePair`].
+///
+/// Laid out with `repr(C)` under the `strict` feature, matching the rest of `rkyv`'s
+/// strict-layout types.
+#[cfg_attr(feature = "strict", repr(C))]
+pub struct ArchivedKeyValuePair<TKey: Archive, TValue: Archive> {
+    pub key: Archived<TKey>,
+    pub value: Archived<TValue>,
+}
+
+impl<TKey: Archive, TValue: Archive> Archive for KeyValuePair<TKey, TValue> {
+    type Archived = ArchivedKeyValuePair<TKey, TValue>;
+    type Resolver = (TKey::Resolver, TValue::Resolver);
+
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        let (fp, fo) = out_field!(out.key);
+        self.key.resolve(pos + fp, resolver.0, fo);
+
+        let (fp, fo) = out_field!(out.value);
+        self.value.resolve(pos + fp, resolver.1, fo);
+    }
+}
+
+impl<TKey, TValue, S> Serialize<S> for KeyValuePair<TKey, TValue>
+where
+    TKey: Serialize<S>,
+    TValue: Serialize<S>,
+    S: Fallible + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok((
+            self.key.serialize(serializer)?,
+            self.value.serialize(serializer)?,
+        ))
+    }
+}
+
+impl<TKey, TValue, D> Deserialize<KeyValuePair<TKey, TValue>, D> for ArchivedKeyValuePair<TKey, TValue>
+where
+    TKey: Archive,
+    TValue: Archive,
+    Archived<TKey>: Deserialize<TKey, D>,
+    Archived<TValue>: Deserialize<TValue, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<KeyValuePair<TKey, TValue>, D::Error> {
+        Ok(KeyValuePair {
+            key: self.key.deserialize(deserializer)?,
+            value: self.value.deserialize(deserializer)?,
+        })
+    }
+}
+
+impl<TKey: Archive, TValue: Archive> PartialEq for ArchivedKeyValuePair<TKey, TValue>
+where
+    Archived<TKey>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<TKey: Archive, TValue: Archive> Eq for ArchivedKeyValuePair<TKey, TValue> where Archived<TKey>: Eq {}
+
+impl<TKey: Archive, TValue: Archive> PartialOrd for ArchivedKeyValuePair<TKey, TValue>
+where
+    Archived<TKey>: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.key.partial_cmp(&other.key)
+    }
+}
+
+impl<TKey: Archive, TValue: Archive> Ord for ArchivedKeyValuePair<TKey, TValue>
+where
+    Archived<TKey>: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl<TKey: Archive, TValue: Archive> Hash for ArchivedKeyValuePair<TKey, TValue>
+where
+    Archived<TKey>: Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.key.hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::Infallible;
+
+    use super::*;
+
+    #[test]
+    fn round_trips_through_archive_and_deserialize() {
+        let pair = KeyValuePair::new(1, String::from("hello"));
+
+        let bytes = rkyv::to_bytes::<_, 256>(&pair).expect("failed to serialize pair");
+        let archived = unsafe { rkyv::archived_root::<KeyValuePair<i32, String>>(&bytes) };
+
+        assert_eq!(archived.key, 1);
+        assert_eq!(archived.value, "hello");
+
+        let deserialized: KeyValuePair<i32, String> = archived
+            .deserialize(&mut Infallible)
+            .expect("failed to deserialize pair");
+
+        assert_eq!(deserialized.key, pair.key);
+        assert_eq!(deserialized.value, pair.value);
+    }
+
+    #[test]
+    fn archived_comparisons_ignore_value() {
+        let a = KeyValuePair::new(1, String::from("a"));
+        let b = KeyValuePair::new(1, String::from("b"));
+        let c = KeyValuePair::new(2, String::from("a"));
+
+        let a_bytes = rkyv::to_bytes::<_, 256>(&a).expect("failed to serialize a");
+        let b_bytes = rkyv::to_bytes::<_, 256>(&b).expect("failed to serialize b");
+        let c_bytes = rkyv::to_bytes::<_, 256>(&c).expect("failed to serialize c");
+
+        let a_archived = unsafe { rkyv::archived_root::<KeyValuePair<i32, String>>(&a_bytes) };
+        let b_archived = unsafe { rkyv::archived_root::<KeyValuePair<i32, String>>(&b_bytes) };
+        let c_archived = unsafe { rkyv::archived_root::<KeyValuePair<i32, String>>(&c_bytes) };
+
+        assert!(a_archived == b_archived);
+        assert!(a_archived != c_archived);
+        assert_eq!(a_archived.cmp(c_archived), Ordering::Less);
+    }
+}